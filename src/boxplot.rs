@@ -0,0 +1,135 @@
+//! A standard statistical chart summarizing the distribution of a data set.
+
+use super::GridPrint;
+use sf;
+use std::cmp::Ordering;
+
+/// The five-number summary of a data set, plus the whisker extents and any outliers, all in the
+/// same domain as the mapped values.
+struct Summary {
+    min: f32,
+    q1: f32,
+    median: f32,
+    q3: f32,
+    max: f32,
+    whisker_min: f32,
+    whisker_max: f32,
+    outliers: Vec<f32>
+}
+
+/// Linearly interpolated quantile of a sorted slice, for `p` in `[0.0, 1.0]`.
+fn quantile(sorted: &[f32], p: f32) -> f32 {
+    if sorted.len() == 1 {
+        return sorted[0];
+    }
+    let idx = p * (sorted.len() - 1) as f32;
+    let lo = idx.floor() as usize;
+    let hi = idx.ceil() as usize;
+    let frac = idx - lo as f32;
+    sorted[lo] + (sorted[hi] - sorted[lo]) * frac
+}
+
+/// Maps `v` from `[min, max]` onto a column in `[0, width)`.
+fn plot_col(v: f32, min: f32, max: f32, width: usize) -> usize {
+    if width == 0 {
+        return 0;
+    }
+    let frac = if (max - min).abs() < ::std::f32::EPSILON { 0.5 } else { (v - min) / (max - min) };
+    (frac * (width - 1) as f32).round().max(0.0).min((width - 1) as f32) as usize
+}
+
+/// A box-and-whisker plot, rendered as a single horizontal row of characters. Like `HBar`,
+/// `width` controls how many characters the plot spans.
+pub struct BoxPlot {
+    width: usize,
+    sorted: Vec<f32>,
+    show_outliers: bool
+}
+
+impl BoxPlot {
+    /// Build a box plot over `data`, with `key` mapping each value into the domain being
+    /// summarized. Outlier detection (points beyond 1.5x IQR, drawn separately from the
+    /// whiskers) is enabled by default; see `set_show_outliers`.
+    pub fn new<V>(width: usize, data: &[V], key: Box<Fn(&V) -> f32>) -> BoxPlot {
+        let mut sorted: Vec<f32> = data.iter().map(|v| (*key)(v)).collect();
+        sorted.sort_by(|a, b| a.partial_cmp(b).unwrap_or(Ordering::Equal));
+        BoxPlot {
+            width: width,
+            sorted: sorted,
+            show_outliers: true
+        }
+    }
+
+    /// Toggle the 1.5x IQR outlier rule. When disabled, the whiskers simply span the full
+    /// min/max of the data.
+    pub fn set_show_outliers(&mut self, show: bool) {
+        self.show_outliers = show;
+    }
+
+    fn summary(&self) -> Option<Summary> {
+        if self.sorted.is_empty() {
+            return None;
+        }
+        let min = self.sorted[0];
+        let max = self.sorted[self.sorted.len() - 1];
+        let q1 = quantile(&self.sorted, 0.25);
+        let median = quantile(&self.sorted, 0.5);
+        let q3 = quantile(&self.sorted, 0.75);
+        if !self.show_outliers {
+            return Some(Summary { min: min, q1: q1, median: median, q3: q3, max: max,
+                                   whisker_min: min, whisker_max: max, outliers: Vec::new() });
+        }
+        let iqr = q3 - q1;
+        let lower_fence = q1 - 1.5 * iqr;
+        let upper_fence = q3 + 1.5 * iqr;
+        let whisker_min = self.sorted.iter().cloned().filter(|&v| v >= lower_fence).fold(max, f32::min);
+        let whisker_max = self.sorted.iter().cloned().filter(|&v| v <= upper_fence).fold(min, f32::max);
+        let outliers = self.sorted.iter().cloned().filter(|&v| v < lower_fence || v > upper_fence).collect();
+        Some(Summary { min: min, q1: q1, median: median, q3: q3, max: max,
+                        whisker_min: whisker_min, whisker_max: whisker_max, outliers: outliers })
+    }
+}
+
+impl GridPrint for BoxPlot {
+    fn get_size(&self) -> (usize, usize) {
+        (self.width, 1)
+    }
+
+    fn get_cell(&self, x: usize, _y: usize) -> sf::ColorChar {
+        let summary = match self.summary() {
+            Some(s) => s,
+            None => return sf::ColorChar(0xE7, 0x10, ' ')
+        };
+        let domain_min = summary.min.min(summary.whisker_min);
+        let domain_max = summary.max.max(summary.whisker_max);
+        let col = |v: f32| plot_col(v, domain_min, domain_max, self.width);
+
+        let (c_whisker_min, c_q1, c_median, c_q3, c_whisker_max) =
+            (col(summary.whisker_min), col(summary.q1), col(summary.median), col(summary.q3), col(summary.whisker_max));
+
+        if x == c_median {
+            return sf::ColorChar(0xE7, 0x10, '\u{2503}');
+        }
+        if x == c_q1 || x == c_q3 {
+            return sf::ColorChar(0xE7, 0x10, '\u{2502}');
+        }
+        if x > c_q1 && x < c_q3 {
+            return sf::ColorChar(0xE7, 0x10, '\u{2591}');
+        }
+        if x == c_whisker_min {
+            return sf::ColorChar(0xE7, 0x10, '\u{251C}');
+        }
+        if x == c_whisker_max {
+            return sf::ColorChar(0xE7, 0x10, '\u{2524}');
+        }
+        if x > c_whisker_min && x < c_whisker_max {
+            return sf::ColorChar(0xE7, 0x10, '\u{2500}');
+        }
+        for &v in summary.outliers.iter() {
+            if col(v) == x {
+                return sf::ColorChar(0xE7, 0x10, '\u{00B7}');
+            }
+        }
+        sf::ColorChar(0xE7, 0x10, ' ')
+    }
+}
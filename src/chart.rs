@@ -0,0 +1,164 @@
+//! Axis, tick, and label decoration for any `GridPrint`, mirroring the Axis/Dataset/bounds model
+//! used by other terminal charting widgets.
+
+use super::{GridPrint, Graph};
+use sf;
+
+/// Number of tick marks drawn along each axis.
+const TICKS: usize = 3;
+
+const FG: u8 = 0xE7;
+const BG: u8 = 0x10;
+
+/// Wraps a `GridPrint` with left and bottom axes labelled from real data bounds.
+///
+/// The bounds (eg. `(0.0, 100.0)`) only set the tick labels; the wrapped `GridPrint` is drawn
+/// unchanged, so a plain `Graph` still needs its data normalized into `[0.0, 1.0)`. A `Graph`
+/// built with `hist_auto`/`scatter_auto` takes raw values and can be wrapped with `from_graph`.
+pub struct Chart<G> {
+    inner: G,
+    x_bounds: (f32, f32),
+    y_bounds: (f32, f32),
+    title: Option<Vec<char>>,
+    left_gutter: usize
+}
+
+impl <G: GridPrint> Chart<G> {
+    /// Wrap `inner` with axes spanning `x_bounds` horizontally and `y_bounds` vertically.
+    pub fn new(inner: G, x_bounds: (f32, f32), y_bounds: (f32, f32)) -> Chart<G> {
+        let left_gutter = 1 + label(y_bounds.0).len().max(label(y_bounds.1).len());
+        Chart {
+            inner: inner,
+            x_bounds: x_bounds,
+            y_bounds: y_bounds,
+            title: None,
+            left_gutter: left_gutter
+        }
+    }
+
+    /// Attach a title, printed centered above the graph.
+    pub fn set_title(&mut self, title: &str) {
+        self.title = Some(title.chars().collect());
+    }
+}
+
+impl <D> Chart<Graph<D>> {
+    /// Wrap a `Graph` built with `hist_auto`/`scatter_auto`, reading its already-computed
+    /// `x_bounds`/`y_bounds` instead of making the caller recompute and pass them separately.
+    pub fn from_graph(inner: Graph<D>) -> Chart<Graph<D>> {
+        let x_bounds = inner.x_bounds();
+        let y_bounds = inner.y_bounds();
+        Chart::new(inner, x_bounds, y_bounds)
+    }
+}
+
+/// Formats a bound value the way tick labels are rendered.
+fn label(v: f32) -> String {
+    format!("{:.2}", v)
+}
+
+/// Evenly spaced rows (top to bottom) at which to draw a y-axis tick, for a graph `height` rows
+/// tall.
+fn tick_rows(height: usize) -> Vec<usize> {
+    if height <= 1 {
+        return vec![0; height];
+    }
+    let mut rows: Vec<usize> = (0..TICKS).map(|i| (height - 1) * i / (TICKS - 1)).collect();
+    rows.dedup();
+    rows
+}
+
+/// Evenly spaced columns (left to right) at which to draw an x-axis tick, for a graph `width`
+/// columns wide.
+fn tick_cols(width: usize) -> Vec<usize> {
+    tick_rows(width)
+}
+
+impl <G: GridPrint> GridPrint for Chart<G> {
+    fn get_size(&self) -> (usize, usize) {
+        let (w, h) = self.inner.get_size();
+        (w + self.left_gutter, h + 2 + 1)
+    }
+
+    fn get_cell(&self, x: usize, y: usize) -> sf::ColorChar {
+        let (w, h) = self.inner.get_size();
+        if y == 0 {
+            return self.title_cell(x, w + self.left_gutter);
+        }
+        let y = y - 1;
+        let axis_row = h;
+        let label_row = h + 1;
+        if y < h {
+            if x < self.left_gutter {
+                return self.y_axis_cell(x, y, h);
+            }
+            return self.inner.get_cell(x - self.left_gutter, y);
+        }
+        if y == axis_row {
+            return self.x_axis_cell(x, w);
+        }
+        if y == label_row {
+            return self.x_label_cell(x, w);
+        }
+        sf::ColorChar(FG, BG, ' ')
+    }
+}
+
+impl <G: GridPrint> Chart<G> {
+    fn y_axis_cell(&self, x: usize, row: usize, height: usize) -> sf::ColorChar {
+        if x == self.left_gutter - 1 {
+            let ch = if tick_rows(height).contains(&row) { '\u{2524}' } else { '\u{2502}' };
+            return sf::ColorChar(FG, BG, ch);
+        }
+        if tick_rows(height).contains(&row) {
+            let frac = if height <= 1 { 0.5 } else { 1.0 - row as f32 / (height - 1) as f32 };
+            let value = self.y_bounds.0 + (self.y_bounds.1 - self.y_bounds.0) * frac;
+            let text = label(value);
+            let start = (self.left_gutter - 1).saturating_sub(text.len());
+            if x >= start && x < self.left_gutter - 1 {
+                let ch = text.as_bytes()[x - start] as char;
+                return sf::ColorChar(FG, BG, ch);
+            }
+        }
+        sf::ColorChar(FG, BG, ' ')
+    }
+
+    fn x_axis_cell(&self, x: usize, width: usize) -> sf::ColorChar {
+        if x == self.left_gutter - 1 {
+            return sf::ColorChar(FG, BG, '\u{2514}');
+        }
+        if x < self.left_gutter || x >= self.left_gutter + width {
+            return sf::ColorChar(FG, BG, ' ');
+        }
+        let ch = if tick_cols(width).contains(&(x - self.left_gutter)) { '\u{252C}' } else { '\u{2500}' };
+        sf::ColorChar(FG, BG, ch)
+    }
+
+    fn x_label_cell(&self, x: usize, width: usize) -> sf::ColorChar {
+        if x < self.left_gutter {
+            return sf::ColorChar(FG, BG, ' ');
+        }
+        let col = x - self.left_gutter;
+        for &tick in tick_cols(width).iter() {
+            let frac = if width <= 1 { 0.5 } else { tick as f32 / (width - 1) as f32 };
+            let value = self.x_bounds.0 + (self.x_bounds.1 - self.x_bounds.0) * frac;
+            let text = label(value);
+            let start = tick.min(width.saturating_sub(text.len()));
+            if col >= start && col < start + text.len() {
+                let ch = text.as_bytes()[col - start] as char;
+                return sf::ColorChar(FG, BG, ch);
+            }
+        }
+        sf::ColorChar(FG, BG, ' ')
+    }
+
+    fn title_cell(&self, x: usize, total_width: usize) -> sf::ColorChar {
+        if let Some(ref title) = self.title {
+            let start = total_width.saturating_sub(title.len()) / 2;
+            if x >= start && x < start + title.len() {
+                return sf::ColorChar(FG, BG, title[x - start]);
+            }
+        }
+        sf::ColorChar(FG, BG, ' ')
+    }
+}
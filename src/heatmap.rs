@@ -0,0 +1,91 @@
+//! Color-mapped matrix visualization (heat maps / `matshow`), using `sf::ColorChar`'s independent
+//! foreground and background color indices to pack two vertically stacked samples per cell.
+
+use super::GridPrint;
+use sf;
+use std::mem;
+
+/// Maps a normalized `[0.0, 1.0]` intensity to the xterm-256 grayscale ramp (indices 232-255),
+/// using the same index for both the foreground and background slot.
+pub fn grayscale(value: f32) -> (u8, u8) {
+    let v = value.max(0.0).min(1.0);
+    let level = 232 + (v * 23.0).round() as u8;
+    (level, level)
+}
+
+/// A general structure representing a color-mapped matrix of data, analogous to `Graph` but for
+/// continuous values instead of booleans.
+///
+/// Each terminal cell packs two vertically stacked samples using `▀` (fg = top sample, bg =
+/// bottom sample), giving the 2x vertical resolution the block trick gives monochrome graphs.
+pub struct HeatMap<D> {
+    buf: sf::Buffer<f32>,
+    data: D,
+    renderer: Box<Fn(&D, usize, usize) -> f32>,
+    gradient: Box<Fn(f32) -> (u8, u8)>
+}
+
+impl <D> HeatMap<D> {
+    /// Render the heat map data to the internal buffer. This should be called automatically in
+    /// all cases.
+    pub fn render(&mut self) {
+        for x in 0..self.buf.width {
+            for y in 0..self.buf.height {
+                self.buf.set(x, y, (*self.renderer)(&self.data, x, y));
+            }
+        }
+    }
+    /// Set the heat map data, returning ownership of the previous data.
+    pub fn set_data(&mut self, mut data: D) -> D {
+        mem::swap(&mut data, &mut self.data);
+        self.render();
+        data
+    }
+    /// Swap in a different value-to-color ramp. Defaults to `grayscale`.
+    pub fn set_gradient(&mut self, gradient: Box<Fn(f32) -> (u8, u8)>) {
+        self.gradient = gradient;
+    }
+}
+
+impl <V> HeatMap<Vec<Vec<V>>> where V: 'static {
+    /// Create a heat map from a grid of rows, with `key` mapping each value into a normalized
+    /// `[0.0, 1.0]` intensity. `width`/`height` are effective values, not `GridPrint` values:
+    /// divide `height` by two to get the number of characters the heat map will take up
+    /// vertically.
+    pub fn matrix(width: usize, height: usize, key: Box<Fn(&V) -> f32>) -> HeatMap<Vec<Vec<V>>> {
+        let thing = move |dat: &Vec<Vec<V>>, x: usize, y: usize| {
+            // buf row 0 is the bottom of the display, but matrix row 0 should render at the
+            // top (matshow convention), so read rows back to front from the top of the buffer.
+            let row = height - 1 - y;
+            if row >= dat.len() {
+                0.0
+            } else {
+                if x >= dat[row].len() {
+                    0.0
+                } else {
+                    key(&dat[row][x])
+                }
+            }
+        };
+        HeatMap {
+            buf: sf::Buffer::new(width, height, 0.0),
+            data: Vec::new(),
+            renderer: Box::new(thing),
+            gradient: Box::new(grayscale)
+        }
+    }
+}
+
+impl <D> GridPrint for HeatMap<D> {
+    fn get_size(&self) -> (usize, usize) {
+        (self.buf.width, self.buf.height / 2)
+    }
+    fn get_cell(&self, x: usize, y: usize) -> sf::ColorChar {
+        let base = self.buf.height - (y + 1) * 2;
+        let top = self.buf.get(x, base + 1);
+        let bottom = self.buf.get(x, base);
+        let (fg, _) = (self.gradient)(top);
+        let (_, bg) = (self.gradient)(bottom);
+        sf::ColorChar(fg, bg, '\u{2580}')
+    }
+}
@@ -0,0 +1,59 @@
+//! Compositing multiple `GridPrint` widgets into a single tiled dashboard, the way terminal UI
+//! buffers merge separate regions into one screen.
+
+use super::GridPrint;
+use sf;
+
+/// A child widget placed at `(x, y)` within a `Layout`, spanning `w` columns and `h` rows.
+pub struct Panel {
+    child: Box<GridPrint>,
+    x: usize,
+    y: usize,
+    w: usize,
+    h: usize
+}
+
+/// Tiles several `GridPrint` widgets into one grid, so a histogram and an `HBar` (or any other
+/// mix of widgets) can be printed together with a single `print()` call.
+pub struct Layout {
+    width: usize,
+    height: usize,
+    panels: Vec<Panel>
+}
+
+impl Layout {
+    /// Create an empty layout of the given overall size.
+    pub fn new(width: usize, height: usize) -> Layout {
+        Layout {
+            width: width,
+            height: height,
+            panels: Vec::new()
+        }
+    }
+
+    /// Place `child` at `(x, y)`, spanning `w` columns and `h` rows. Panels added later are drawn
+    /// on top of ones added earlier where they overlap.
+    pub fn add(&mut self, child: Box<GridPrint>, x: usize, y: usize, w: usize, h: usize) {
+        self.panels.push(Panel { child: child, x: x, y: y, w: w, h: h });
+    }
+}
+
+impl GridPrint for Layout {
+    fn get_size(&self) -> (usize, usize) {
+        (self.width, self.height)
+    }
+
+    fn get_cell(&self, x: usize, y: usize) -> sf::ColorChar {
+        for panel in self.panels.iter().rev() {
+            if x < panel.x || y < panel.y || x >= panel.x + panel.w || y >= panel.y + panel.h {
+                continue;
+            }
+            let (cw, ch) = panel.child.get_size();
+            let (lx, ly) = (x - panel.x, y - panel.y);
+            if lx < cw && ly < ch {
+                return panel.child.get_cell(lx, ly);
+            }
+        }
+        sf::ColorChar(0xE7, 0x10, ' ')
+    }
+}
@@ -8,6 +8,18 @@
 extern crate starfield_render;
 use starfield_render as sf;
 use std::mem;
+use std::f32;
+use std::rc::Rc;
+use std::cell::Cell;
+
+mod chart;
+pub use chart::Chart;
+mod heatmap;
+pub use heatmap::HeatMap;
+mod layout;
+pub use layout::{Layout, Panel};
+mod boxplot;
+pub use boxplot::BoxPlot;
 
 static hblocks: [char; 9] = [' ','▏','▎','▍','▌','▋','▊','▉','█'];
 static vblocks: [char; 9] = [' ','▁','▂','▃','▄','▅','▆','▇','█'];
@@ -31,24 +43,75 @@ pub trait GridPrint {
     }
 }
 
+/// Selects how a `Graph`'s boolean pixel buffer is packed into terminal characters.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum Resolution {
+    /// Unicode block elements, doubling resolution in both axes.
+    Block,
+    /// Unicode Braille patterns, giving 2 columns x 4 rows of dots per character.
+    Braille
+}
+
+/// Packs a 2x4 patch of the buffer starting at `(bx, by)` into a single Braille character.
+fn braille_cell(buf: &sf::Buffer<bool>, bx: usize, by: usize) -> char {
+    static col_bits: [[u32; 4]; 2] = [[0x01, 0x02, 0x04, 0x40], [0x08, 0x10, 0x20, 0x80]];
+    let mut mask = 0;
+    for col in 0..2 {
+        for row in 0..4 {
+            if buf.get(bx + col, by + row) {
+                // buf rows run bottom-up within the patch (same convention as the Block path
+                // and HeatMap), but Braille dot numbering runs top-down, so invert here.
+                mask |= col_bits[col][3 - row];
+            }
+        }
+    }
+    char::from_u32(0x2800 + mask).unwrap_or(' ')
+}
+
+/// How a `Graph` turns its data into the boolean pixel buffer.
+enum Renderer<D> {
+    /// Queried independently for every pixel; used by graphs where each pixel only depends on
+    /// the data at that point (`hist`, `scatter`, ...).
+    PerPixel(Box<Fn(&D, usize, usize) -> bool>),
+    /// Given the whole buffer to draw into directly; used by graphs where a single data point
+    /// can affect many pixels at once (`line`'s rasterized segments).
+    Bitmap(Box<Fn(&D, &mut sf::Buffer<bool>)>)
+}
+
 /// A general structure representing a simple 2D graph.
 ///
 /// Each 'pixel' on this graph can be either on or off, so heat maps are out unless dithering or
-/// contour lines are involved. `Graph` makes use of unicode block elements (eg. `▚`,  `▛`, `▗`) to
-/// double its effective resolution.
+/// contour lines are involved. By default `Graph` makes use of unicode block elements (eg. `▚`,
+/// `▛`, `▗`) to double its effective resolution, but it can also be switched to `Resolution::Braille`
+/// for 2x4 dots per character.
 pub struct Graph<D> {
     buf: sf::Buffer<bool>,
     data: D,
-    renderer: Box<Fn(&D, usize, usize) -> bool>
+    renderer: Renderer<D>,
+    resolution: Resolution,
+    bounds: Rc<Cell<((f32, f32), (f32, f32))>>
 }
 
 impl <D> Graph<D> {
     /// Render the graph data to the internal buffer. This should be called automatically in all
     /// cases.
     pub fn render(&mut self) {
-        for x in 0..self.buf.width {
-            for y in 0..self.buf.height {
-                self.buf.set(x, y, (*self.renderer)(&self.data, x, y));
+        match self.renderer {
+            Renderer::PerPixel(ref f) => {
+                for x in 0..self.buf.width {
+                    for y in 0..self.buf.height {
+                        let v = f(&self.data, x, y);
+                        self.buf.set(x, y, v);
+                    }
+                }
+            },
+            Renderer::Bitmap(ref f) => {
+                for x in 0..self.buf.width {
+                    for y in 0..self.buf.height {
+                        self.buf.set(x, y, false);
+                    }
+                }
+                f(&self.data, &mut self.buf);
             }
         }
     }
@@ -58,6 +121,43 @@ impl <D> Graph<D> {
         self.render();
         data
     }
+    /// Switch between block and Braille rendering. Defaults to `Resolution::Block`.
+    pub fn set_resolution(&mut self, resolution: Resolution) {
+        self.resolution = resolution;
+    }
+    /// The horizontal domain the most recent render normalized against. Only meaningful for
+    /// graphs built with `scatter_auto`; otherwise this is `(0.0, 1.0)`.
+    pub fn x_bounds(&self) -> (f32, f32) {
+        self.bounds.get().0
+    }
+    /// The vertical domain the most recent render normalized against. Only meaningful for graphs
+    /// built with `hist_auto` or `scatter_auto`; otherwise this is `(0.0, 1.0)`.
+    pub fn y_bounds(&self) -> (f32, f32) {
+        self.bounds.get().1
+    }
+}
+
+/// Scans `data` through `key`, returning `(min, max)`. Falls back to `(0.0, 1.0)` on empty data.
+fn min_max<V, F: Fn(&V) -> f32>(data: &[V], key: &F) -> (f32, f32) {
+    let mut min = f32::INFINITY;
+    let mut max = f32::NEG_INFINITY;
+    for v in data {
+        let val = key(v);
+        if val < min { min = val; }
+        if val > max { max = val; }
+    }
+    if min > max { (0.0, 1.0) } else { (min, max) }
+}
+
+/// Normalizes `value` from `(min, max)` into `[0.0, 1.0]`, treating an empty or zero-width range
+/// as a single centered value rather than dividing by zero.
+fn normalize(value: f32, (min, max): (f32, f32)) -> f32 {
+    let range = max - min;
+    if range.abs() < f32::EPSILON {
+        0.5
+    } else {
+        (value - min) / range
+    }
 }
 
 impl <V> Graph<Vec<V>> where V: 'static{
@@ -81,7 +181,40 @@ impl <V> Graph<Vec<V>> where V: 'static{
         Graph {
             buf: buf,
             data: Vec::new(),
-            renderer: Box::new(thing)
+            renderer: Renderer::PerPixel(Box::new(thing)),
+            resolution: Resolution::Block,
+            bounds: Rc::new(Cell::new(((0.0, 1.0), (0.0, 1.0))))
+        }
+    }
+    /// Create a histogram like `hist`, but `key` returns raw values instead of values already
+    /// normalized into `[0.0, 1.0)`. The vertical domain is recomputed from the data's min/max
+    /// once per render (not once per pixel) and can be read back with `y_bounds`.
+    pub fn hist_auto(width: usize, height: usize, key: Box<Fn(&V) -> f32>) -> Graph<Vec<V>>
+    {
+        let bounds = Rc::new(Cell::new(((0.0, 1.0), (0.0, 1.0))));
+        let bounds_ref = bounds.clone();
+        let thing = move |dat: &Vec<V>, buf: &mut sf::Buffer<bool>| {
+            if dat.len() <= 1 {
+                return;
+            }
+            let y_bounds = min_max(dat, &key);
+            bounds_ref.set(((0.0, 1.0), y_bounds));
+            for x in 0..width {
+                let pos = dat.len() as f32 * x as f32 / width as f32;
+                let index = pos as usize;
+                let value = normalize(key(&dat[index]), y_bounds);
+                for y in 0..height {
+                    let h = y as f32 / height as f32;
+                    buf.set(x, y, value >= h);
+                }
+            }
+        };
+        Graph {
+            buf: sf::Buffer::new(width, height, false),
+            data: Vec::new(),
+            renderer: Renderer::Bitmap(Box::new(thing)),
+            resolution: Resolution::Block,
+            bounds: bounds
         }
     }
     pub fn scatter(width: usize, height: usize, hkey: Box<Fn(&V) -> f32>, vkey: Box<Fn(&V) -> f32>) -> Graph<Vec<V>>
@@ -101,19 +234,107 @@ impl <V> Graph<Vec<V>> where V: 'static{
         Graph {
             buf: buf,
             data: Vec::new(),
-            renderer: Box::new(thing)
+            renderer: Renderer::PerPixel(Box::new(thing)),
+            resolution: Resolution::Block,
+            bounds: Rc::new(Cell::new(((0.0, 1.0), (0.0, 1.0))))
         }
     }
+    /// Create a scatter plot like `scatter`, but `hkey`/`vkey` return raw values instead of
+    /// values already normalized into `[0.0, 1.0)`. Both domains are recomputed from the data's
+    /// min/max once per render (not once per pixel) and can be read back with
+    /// `x_bounds`/`y_bounds`.
+    pub fn scatter_auto(width: usize, height: usize, hkey: Box<Fn(&V) -> f32>, vkey: Box<Fn(&V) -> f32>) -> Graph<Vec<V>>
+    {
+        let bounds = Rc::new(Cell::new(((0.0, 1.0), (0.0, 1.0))));
+        let bounds_ref = bounds.clone();
+        let thing = move |dat: &Vec<V>, buf: &mut sf::Buffer<bool>| {
+            let x_bounds = min_max(dat, &hkey);
+            let y_bounds = min_max(dat, &vkey);
+            bounds_ref.set((x_bounds, y_bounds));
+            for val in dat {
+                let (a, b) = (normalize(hkey(val), x_bounds), normalize(vkey(val), y_bounds));
+                let (a, b) = (a*width as f32, b*height as f32);
+                // `normalize` maps the max to 1.0, which would land one past the last pixel.
+                let (px, py) = ((a.floor() as usize).min(width.saturating_sub(1)),
+                                (b.floor() as usize).min(height.saturating_sub(1)));
+                if px < width && py < height {
+                    buf.set(px, py, true);
+                }
+            }
+        };
+        Graph {
+            buf: sf::Buffer::new(width, height, false),
+            data: Vec::new(),
+            renderer: Renderer::Bitmap(Box::new(thing)),
+            resolution: Resolution::Block,
+            bounds: bounds
+        }
+    }
+    /// Create a connected line plot. Like `scatter`, `hkey`/`vkey` map values into `[0.0, 1.0)`,
+    /// but consecutive points are joined by a Bresenham-rasterized segment instead of being left
+    /// as isolated pixels, so sampled functions render as curves rather than dotted clouds.
+    pub fn line(width: usize, height: usize, hkey: Box<Fn(&V) -> f32>, vkey: Box<Fn(&V) -> f32>) -> Graph<Vec<V>>
+    {
+        let thing = move |dat: &Vec<V>, buf: &mut sf::Buffer<bool>| {
+            for pair in dat.windows(2) {
+                let (x0, y0) = buffer_point(&pair[0], &hkey, &vkey, width, height);
+                let (x1, y1) = buffer_point(&pair[1], &hkey, &vkey, width, height);
+                draw_segment(buf, x0, y0, x1, y1);
+            }
+        };
+        Graph {
+            buf: sf::Buffer::new(width, height, false),
+            data: Vec::new(),
+            renderer: Renderer::Bitmap(Box::new(thing)),
+            resolution: Resolution::Block,
+            bounds: Rc::new(Cell::new(((0.0, 1.0), (0.0, 1.0))))
+        }
+    }
+}
+
+/// Maps a value into buffer pixel coordinates via `hkey`/`vkey`, clipped to the buffer bounds.
+fn buffer_point<V, H: Fn(&V) -> f32, Vk: Fn(&V) -> f32>(val: &V, hkey: &H, vkey: &Vk, width: usize, height: usize) -> (i64, i64) {
+    let x = (hkey(val) * width as f32).floor() as i64;
+    let y = (vkey(val) * height as f32).floor() as i64;
+    (x.max(0).min(width as i64 - 1), y.max(0).min(height as i64 - 1))
+}
+
+/// Rasterizes the segment from `(x0,y0)` to `(x1,y1)` directly into `buf` using Bresenham's
+/// algorithm, setting every pixel along the way (including both endpoints) to `true`.
+fn draw_segment(buf: &mut sf::Buffer<bool>, x0: i64, y0: i64, x1: i64, y1: i64) {
+    let dx = (x1 - x0).abs();
+    let dy = -(y1 - y0).abs();
+    let sx = if x0 < x1 { 1 } else { -1 };
+    let sy = if y0 < y1 { 1 } else { -1 };
+    let mut err = dx + dy;
+    let (mut x, mut y) = (x0, y0);
+    loop {
+        buf.set(x as usize, y as usize, true);
+        if x == x1 && y == y1 {
+            break;
+        }
+        let e2 = 2 * err;
+        if e2 >= dy { err += dy; x += sx; }
+        if e2 <= dx { err += dx; y += sy; }
+    }
 }
 
 impl <D> GridPrint for Graph<D> {
     fn get_size(&self) -> (usize, usize)
     {
-        (self.buf.width / 2,self.buf.height / 2)
+        match self.resolution {
+            Resolution::Block => (self.buf.width / 2, self.buf.height / 2),
+            Resolution::Braille => (self.buf.width / 2, self.buf.height / 4)
+        }
     }
     fn get_cell(&self, x:usize, y:usize) -> sf::ColorChar
     {
-        sf::ColorChar(0xE7, 0x10, sf::grid_cell(&self.buf, x*2, self.buf.height - (y+1)*2))
+        match self.resolution {
+            Resolution::Block =>
+                sf::ColorChar(0xE7, 0x10, sf::grid_cell(&self.buf, x*2, self.buf.height - (y+1)*2)),
+            Resolution::Braille =>
+                sf::ColorChar(0xE7, 0x10, braille_cell(&self.buf, x*2, self.buf.height - (y+1)*4))
+        }
     }
 }
 